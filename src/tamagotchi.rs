@@ -1,3 +1,9 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
 /// This is the container for the [Tamagotchi].
 ///
 /// This is where the user will interact with their 'gotchi.
@@ -5,6 +11,59 @@ pub(crate) struct TamagotchiInterface {
     display: InterfaceDisplay,
     actions: Actions,
     tamagotchi: Tamagotchi,
+    /// Where the wrapped [Tamagotchi] is [Tamagotchi::save]d and
+    /// [Tamagotchi::load]ed from.
+    save_path: PathBuf,
+}
+impl TamagotchiInterface {
+    /// Creates a [TamagotchiInterface], loading a previously
+    /// [Tamagotchi::save]d 'gotchi from `save_path` if one exists, and
+    /// falling back to `new_tamagotchi` otherwise.
+    pub(crate) fn new(save_path: impl Into<PathBuf>, new_tamagotchi: Tamagotchi) -> Self {
+        let save_path = save_path.into();
+        let tamagotchi = Tamagotchi::load(&save_path).unwrap_or(new_tamagotchi);
+        Self {
+            display: InterfaceDisplay::Clock,
+            actions: Actions::Feed,
+            tamagotchi,
+            save_path,
+        }
+    }
+    /// Brings the wrapped [Tamagotchi] up to date with however much
+    /// wall-clock time has passed since it was last ticked, evolves it, and
+    /// persists the result. The active [InterfaceDisplay] is not changed or
+    /// redrawn here.
+    pub(crate) fn update(&mut self) {
+        let elapsed = self.tamagotchi.last_updated.elapsed().unwrap_or_default();
+        self.tamagotchi = self.tamagotchi.tick(elapsed).evolve();
+        self.persist();
+    }
+    /// Applies an action to the wrapped [Tamagotchi] and persists the
+    /// result, same as [TamagotchiInterface::update] does for ticks.
+    pub(crate) fn act(&mut self, action: impl FnOnce(&Tamagotchi) -> Tamagotchi) {
+        self.tamagotchi = action(&self.tamagotchi);
+        self.persist();
+    }
+    /// Plays the [Actions::Play] guessing mini-game, applying the result to
+    /// the wrapped [Tamagotchi] and persisting it.
+    pub(crate) fn play(&mut self, guesses: &[Direction]) -> GameResult {
+        let (tamagotchi, result) = self.tamagotchi.play(guesses);
+        self.tamagotchi = tamagotchi;
+        self.persist();
+        result
+    }
+    /// The [Form] to render when [InterfaceDisplay::Character] is active.
+    pub(crate) fn character(&self) -> &Form {
+        &self.tamagotchi.form
+    }
+    /// The 'gotchi's last spoken thought, shown on
+    /// [InterfaceDisplay::Character] and [InterfaceDisplay::ReturningHome].
+    pub(crate) fn thought(&self) -> Option<&str> {
+        self.tamagotchi.last_thought.as_deref()
+    }
+    fn persist(&self) {
+        let _ = self.tamagotchi.save(&self.save_path);
+    }
 }
 /// These are the possible screen displays the user can navigate to.
 pub(crate) enum InterfaceDisplay {
@@ -27,9 +86,42 @@ pub(crate) enum Actions {
     Attention,
     Discipline,
 }
+/// Number of rounds in the [Actions::Play] guessing mini-game; the
+/// 'gotchi's [Direction] must be guessed correctly in a majority of rounds
+/// to win.
+pub(crate) const PLAY_GAME_ROUNDS: usize = 3;
+/// A direction picked by the 'gotchi (and guessed by the player) in the
+/// [Actions::Play] mini-game.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Left,
+    Right,
+}
+impl Direction {
+    fn random() -> Self {
+        match rand::random() {
+            true => Direction::Left,
+            false => Direction::Right,
+        }
+    }
+}
+/// The outcome of a single [Actions::Play] mini-game round.
+pub(crate) struct Round {
+    pub(crate) chosen: Direction,
+    pub(crate) guessed: Direction,
+    pub(crate) won: bool,
+}
+/// Best-of-[PLAY_GAME_ROUNDS] result of the [Actions::Play] mini-game,
+/// returned so an [InterfaceDisplay] can render it turn-by-turn rather than
+/// resolving instantly.
+pub(crate) struct GameResult {
+    pub(crate) rounds: Vec<Round>,
+    pub(crate) won: bool,
+}
 /// Translates to "Egg-watch".
 ///
 /// This is the actual character the user will interact with.
+#[derive(Serialize, Deserialize)]
 pub(crate) struct Tamagotchi {
     name: String,
     gender: Gender,
@@ -37,10 +129,181 @@ pub(crate) struct Tamagotchi {
     weight: f64,
     form: Form,
     status: Status,
+    /// When this 'gotchi was created as a [Form::Tamago] egg, used to derive
+    /// [Tamagotchi::age]. A
+    /// wall-clock timestamp (rather than [std::time::Instant]) so it
+    /// survives [Tamagotchi::save]/[Tamagotchi::load] across process
+    /// restarts.
+    birth: SystemTime,
+    /// When [Status] was last ticked forward.
+    last_updated: SystemTime,
+    /// The last thing the 'gotchi said, refreshed periodically during
+    /// [Tamagotchi::tick]. Surfaced on [InterfaceDisplay::Character] and
+    /// [InterfaceDisplay::ReturningHome].
+    last_thought: Option<String>,
 }
 impl Tamagotchi {
+    /// Advances [Status] decay (or recovery) and [Tamagotchi::age] by
+    /// however much wall-clock time has passed since `last_updated`.
+    pub(crate) fn tick(&self, elapsed: Duration) -> Self {
+        if self.status.dead {
+            return self.unchanged();
+        }
+        let status = self.status.tick(elapsed);
+        let age = self.birth.elapsed().unwrap_or_default().as_secs() / 60;
+        let ticks = elapsed.as_secs() / HUNGER_DECAY_INTERVAL.as_secs();
+        let last_thought = if ticks > 0 && rand::random::<f64>() < SPEAK_PROBABILITY {
+            Some(Self::phrase_for(&status))
+        } else {
+            self.last_thought.clone()
+        };
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form: self.form.clone(),
+            age: age as u32,
+            status,
+            // Advance by whole decay intervals only, carrying the
+            // sub-interval remainder forward instead of snapping to `now` —
+            // otherwise a call cadence faster than `HUNGER_DECAY_INTERVAL`
+            // (e.g. once a second, to keep a live display current) would
+            // compute `ticks == 0` every time and discard all accumulated
+            // time, so decay/soiling/sickness/attention calls could never
+            // trigger.
+            last_updated: self.last_updated + HUNGER_DECAY_INTERVAL * ticks as u32,
+            last_thought,
+            ..*self
+        }
+    }
+    /// Produces a short verb+noun utterance whose tone reflects [Status],
+    /// e.g. "Wash the dogs" — borrowed from the emulator references' random
+    /// thought generator.
+    pub(crate) fn speak(&self) -> String {
+        Self::phrase_for(&self.status)
+    }
+    /// Builds a complaint, cheerful, or neutral verb+noun phrase depending
+    /// on `status`.
+    fn phrase_for(status: &Status) -> String {
+        let verbs = if status.soiled
+            || status.sick
+            || matches!(status.hunger, Hunger::Starving | Hunger::Famished)
+            || matches!(status.mood, Mood::Miserable | Mood::Pessemistic)
+            || matches!(status.health, Health::Neglected | Health::Weak)
+        {
+            COMPLAINT_VERBS
+        } else if matches!(status.care_level(), CareLevel::Good | CareLevel::Perfect) {
+            CHEERFUL_VERBS
+        } else {
+            NEUTRAL_VERBS
+        };
+        let verb = verbs[random_index(verbs.len())];
+        let noun = NOUNS[random_index(NOUNS.len())];
+        format!("{verb} the {noun}")
+    }
+    /// Persists this 'gotchi (and its full [Status]) to `path` as JSON.
+    pub(crate) fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string(self).expect("Tamagotchi is always serializable");
+        fs::write(path, json)
+    }
+    /// Loads a 'gotchi previously [Tamagotchi::save]d from `path`,
+    /// fast-forwarding its decay by however much wall-clock time elapsed
+    /// since `last_updated` while this process was not running.
+    pub(crate) fn load(path: impl AsRef<Path>) -> Option<Self> {
+        let json = fs::read_to_string(path).ok()?;
+        let saved: Self = serde_json::from_str(&json).ok()?;
+        let elapsed = saved.last_updated.elapsed().unwrap_or_default();
+        Some(saved.tick(elapsed).evolve())
+    }
+    /// Returns an unchanged copy of `self`. Used to reject actions once the
+    /// 'gotchi has [Status::dead].
+    fn unchanged(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form: self.form.clone(),
+            last_thought: self.last_thought.clone(),
+            ..*self
+        }
+    }
+    /// Advances [Form] according to [Tamagotchi::age] and the accumulated
+    /// [CareHistory], mirroring the life-cycle timings and care/discipline
+    /// branching documented on [Form] and its nested enums. Meant to run
+    /// alongside [Tamagotchi::tick].
+    pub(crate) fn evolve(&self) -> Self {
+        if self.status.dead {
+            return self.unchanged();
+        }
+        let care_history = self.status.care_history;
+        let form = match &self.form {
+            Form::Tamago if self.age >= EGG_HATCH_AGE_MINUTES => Form::Shirobabytchi,
+            Form::Shirobabytchi if self.age >= CHILD_EVOLVE_AGE_MINUTES => Form::Tonmarutchi,
+            Form::Tonmarutchi if self.age >= TEEN_EVOLVE_AGE_MINUTES => {
+                Form::Teen(Self::teen_form(&care_history, &self.status.discipline))
+            }
+            Form::Teen(teen) if self.age >= ADULT_EVOLVE_AGE_MINUTES => {
+                Form::Adult(Self::adult_form(teen, &care_history))
+            }
+            Form::Adult(adult)
+                if care_history.good_percent() == 100
+                    && matches!(self.status.care_level(), CareLevel::Perfect) =>
+            {
+                match Self::special_form(adult) {
+                    Some(special) => Form::Special(special),
+                    None => self.form.clone(),
+                }
+            }
+            _ => self.form.clone(),
+        };
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form,
+            last_thought: self.last_thought.clone(),
+            ..*self
+        }
+    }
+    /// Branches [TeenForm] by accumulated care quality and
+    /// [Discipline::meter] percentage ("Teen form depends on CareLevel",
+    /// "75% DisciplineLevel").
+    fn teen_form(care_history: &CareHistory, discipline: &Discipline) -> TeenForm {
+        let discipline_percent = discipline.meter() * 100 / 4;
+        if care_history.good_percent() >= 75 && discipline_percent >= 75 {
+            TeenForm::Tongaritchi
+        } else {
+            TeenForm::Hashitamatchi
+        }
+    }
+    /// Selects an [AdultForm] for a given [TeenForm] by accumulated care
+    /// quality.
+    fn adult_form(teen: &TeenForm, care_history: &CareHistory) -> AdultForm {
+        match (teen, care_history.good_percent()) {
+            (TeenForm::Tongaritchi, 90..=100) => AdultForm::Mimitchi,
+            (TeenForm::Tongaritchi, 75..=89) => AdultForm::Pochitchi,
+            (TeenForm::Tongaritchi, _) => AdultForm::Nyatchi,
+            (TeenForm::Hashitamatchi, 50..=100) => AdultForm::Zuccitchi,
+            (TeenForm::Hashitamatchi, 25..=49) => AdultForm::Hashizoutchi,
+            (TeenForm::Hashitamatchi, 10..=24) => AdultForm::Kusatchi,
+            (TeenForm::Hashitamatchi, _) => AdultForm::Takotchi,
+        }
+    }
+    /// Some [AdultForm]s can evolve further into a [SpecialForm].
+    /// [Tamagotchi::evolve] takes this branch when the current [CareLevel] is
+    /// [CareLevel::Perfect] and every tick recorded in [CareHistory] was
+    /// [CareLevel::Good] or better; [CareHistory] does not tell Good ticks
+    /// apart from Perfect ones.
+    fn special_form(adult: &AdultForm) -> Option<SpecialForm> {
+        match adult {
+            AdultForm::Mimitchi => Some(SpecialForm::Sekitoritchi),
+            AdultForm::Pochitchi => Some(SpecialForm::Charitchi),
+            AdultForm::Zuccitchi => Some(SpecialForm::Zatchi),
+            _ => None,
+        }
+    }
     /// feed your tamagotchi
     pub(crate) fn feed(&self) -> Self {
+        if self.status.dead {
+            return self.unchanged();
+        }
         let status = self.status.eat();
         Self {
             name: self.name.clone(),
@@ -48,58 +311,137 @@ impl Tamagotchi {
             weight: self.weight + 0.5,
             form: self.form.clone(),
             status,
+            last_thought: self.last_thought.clone(),
             ..*self
         }
     }
-    /// turn the light on or off
+    /// turn the light on or off. Turning the light off puts the 'gotchi to
+    /// sleep, enabling the mood/health recovery in [Status::tick]; turning
+    /// it back on wakes it.
     pub(crate) fn light(&self) -> Self {
-        let status = match self.status.light {
-            Light::On => Light::Off,
-            Light::Off => Light::On,
-        };
-        match self.status.asleep {
-            true => {}
-            false => {}
+        if self.status.dead {
+            return self.unchanged();
         }
-        Self { ..*self }
-    }
-    /// play with your tamagotchi
-    pub(crate) fn play(&self) -> Self {
-        let status = self.status.play();
+        let status = self.status.toggle_light();
         Self {
             name: self.name.clone(),
             gender: self.gender.clone(),
-            weight: self.weight - 0.2,
             form: self.form.clone(),
             status,
+            last_thought: self.last_thought.clone(),
             ..*self
         }
     }
+    /// Plays the left/right guessing mini-game behind [Actions::Play]: the
+    /// 'gotchi picks a [Direction] each round and `guesses` supplies the
+    /// player's guess for it. Winning a majority of rounds applies
+    /// [Mood::better] and trims extra [Tamagotchi::weight]; losing leaves
+    /// mood unchanged and counts the loss toward neglect.
+    pub(crate) fn play(&self, guesses: &[Direction]) -> (Self, GameResult) {
+        if self.status.dead || guesses.len() != PLAY_GAME_ROUNDS {
+            let result = GameResult {
+                rounds: Vec::new(),
+                won: false,
+            };
+            return (self.unchanged(), result);
+        }
+        let rounds: Vec<Round> = guesses
+            .iter()
+            .map(|&guessed| {
+                let chosen = Direction::random();
+                Round {
+                    chosen,
+                    guessed,
+                    won: chosen == guessed,
+                }
+            })
+            .collect();
+        let won = rounds.iter().filter(|round| round.won).count() * 2 > rounds.len();
+        let (status, weight) = if won {
+            (self.status.play(), self.weight - 0.4)
+        } else {
+            (self.status.lose_game(), self.weight)
+        };
+        let tamagotchi = Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            weight,
+            form: self.form.clone(),
+            status,
+            last_thought: self.last_thought.clone(),
+            ..*self
+        };
+        (tamagotchi, GameResult { rounds, won })
+    }
     /// give your tamagotchi medicine if it's sick
     pub(crate) fn give_medicine(&self) -> Self {
-        Self { ..*self }
+        if self.status.dead {
+            return self.unchanged();
+        }
+        let status = self.status.give_medicine();
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form: self.form.clone(),
+            status,
+            last_thought: self.last_thought.clone(),
+            ..*self
+        }
     }
     /// clean up after your tamagotchi
     pub(crate) fn duck(&self) -> Self {
-        Self { ..*self }
+        if self.status.dead {
+            return self.unchanged();
+        }
+        let status = self.status.duck();
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form: self.form.clone(),
+            status,
+            last_thought: self.last_thought.clone(),
+            ..*self
+        }
     }
-    /// give your tamagotchi attention
+    /// give your tamagotchi attention, acknowledging any outstanding call
     pub(crate) fn attention(&self) -> Self {
-        Self { ..*self }
+        if self.status.dead {
+            return self.unchanged();
+        }
+        let status = self.status.attention();
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form: self.form.clone(),
+            status,
+            last_thought: self.last_thought.clone(),
+            ..*self
+        }
     }
     /// discipline your tamagotchi if they are bad
     pub(crate) fn discipline(&self) -> Self {
-        self
+        if self.status.dead {
+            return self.unchanged();
+        }
+        let status = self.status.discipline();
+        Self {
+            name: self.name.clone(),
+            gender: self.gender.clone(),
+            form: self.form.clone(),
+            status,
+            last_thought: self.last_thought.clone(),
+            ..*self
+        }
     }
 }
 /// The gender of a [Tamagotchi].
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) enum Gender {
     Male,
     Female,
 }
 /// The stage of evolution of a [Tamagotchi]'s life.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) enum Form {
     /// Literally translates to "Egg".
     Tamago,
@@ -120,7 +462,7 @@ impl Default for Form {
     }
 }
 /// Possible teenager [Tamagotchi] forms.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) enum TeenForm {
     /// Good care, 75% [DisciplineLevel]
     Tongaritchi,
@@ -128,7 +470,7 @@ pub(crate) enum TeenForm {
     Hashitamatchi,
 }
 /// Possible adult [Tamagotchi] forms.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) enum AdultForm {
     Mimitchi,
     Pochitchi,
@@ -140,7 +482,7 @@ pub(crate) enum AdultForm {
 }
 /// Some adult 'gotchis can evolve past [AdultForm] and become _special_.
 /// These are the possible special [Tamagotchi] forms.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) enum SpecialForm {
     Sekitoritchi,
     Charitchi,
@@ -158,7 +500,96 @@ trait BetterOrWorse {
     fn better(&self) -> Self;
     fn worse(&self) -> Self;
 }
+/// How often [Hunger] decays by one step. Hunger decays fastest of all
+/// [Status] conditions.
+const HUNGER_DECAY_INTERVAL: Duration = Duration::from_secs(60);
+/// How often [Mood] decays (or recovers, while asleep) by one step.
+const MOOD_DECAY_INTERVAL: Duration = Duration::from_secs(120);
+/// How often [Health] decays (or recovers, while asleep) by one step.
+const HEALTH_DECAY_INTERVAL: Duration = Duration::from_secs(180);
+/// Per-tick probability that an un-soiled room becomes [Status::soiled].
+const SOIL_PROBABILITY: f64 = 0.1;
+/// [Status::neglect] count at which the 'gotchi becomes [Status::sick].
+const NEGLECT_SICK_THRESHOLD: u32 = 3;
+/// Combined feed/duck/play actions beyond which over-care risks making the
+/// 'gotchi sick, mirroring the "feed + wash + play > 24" emulator rule.
+const OVERCARE_THRESHOLD: u32 = 24;
+/// Probability of falling sick from over-care once [OVERCARE_THRESHOLD] is
+/// crossed.
+const OVERCARE_SICK_PROBABILITY: f64 = 0.5;
+/// Per-tick probability that a [Status::sick] 'gotchi dies.
+const SICK_DEATH_PROBABILITY: f64 = 0.33;
+/// Per-tick probability that [Tamagotchi::tick] refreshes
+/// [Tamagotchi::last_thought].
+const SPEAK_PROBABILITY: f64 = 0.5;
+/// Per-tick probability that the 'gotchi issues a call for attention.
+const ATTENTION_CALL_PROBABILITY: f64 = 0.2;
+/// Verbs sampled for complaint utterances, said when hunger/mood/health run
+/// low or the 'gotchi is [Status::soiled]/[Status::sick].
+const COMPLAINT_VERBS: &[&str] = &["Feed", "Wash", "Cure", "Rescue", "Pity"];
+/// Verbs sampled for cheerful utterances, said at high [CareLevel].
+const CHEERFUL_VERBS: &[&str] = &["Hug", "Spoil", "Praise", "Celebrate", "Adore"];
+/// Verbs sampled when neither complaining nor cheerful.
+const NEUTRAL_VERBS: &[&str] = &["Watch", "Count", "Chase", "Greet", "Visit"];
+/// Plural nouns sampled for any [Tamagotchi::speak] utterance.
+const NOUNS: &[&str] = &["dogs", "ducks", "friends", "humans", "stars", "snacks", "clouds"];
+/// Picks a pseudo-random index in `0..len`, in the same lightweight style as
+/// [Behavior::reduce].
+fn random_index(len: usize) -> usize {
+    (rand::random::<f64>() * len as f64) as usize
+}
+/// Age, in minutes, at which [Form::Tamago] hatches into [Form::Shirobabytchi].
+const EGG_HATCH_AGE_MINUTES: u32 = 5;
+/// Age, in minutes, at which [Form::Shirobabytchi] evolves into [Form::Tonmarutchi].
+const CHILD_EVOLVE_AGE_MINUTES: u32 = 65;
+/// Age, in minutes, at which [Form::Tonmarutchi] evolves into a [TeenForm].
+const TEEN_EVOLVE_AGE_MINUTES: u32 = 24 * 60;
+/// Age, in minutes, at which a [TeenForm] evolves into an [AdultForm].
+const ADULT_EVOLVE_AGE_MINUTES: u32 = 3 * 24 * 60;
+/// Running tally of [CareLevel] observed across ticks, consulted by
+/// [Tamagotchi::evolve] for evolution branches that depend on sustained
+/// care rather than a single snapshot.
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+pub(crate) struct CareHistory {
+    good_ticks: u32,
+    bad_ticks: u32,
+}
+impl CareHistory {
+    /// Folds one more observed [CareLevel] into the running tally.
+    fn record(&self, care: CareLevel) -> Self {
+        match care {
+            CareLevel::Good | CareLevel::Perfect => Self {
+                good_ticks: self.good_ticks + 1,
+                ..*self
+            },
+            CareLevel::Bad | CareLevel::BelowAverage => Self {
+                bad_ticks: self.bad_ticks + 1,
+                ..*self
+            },
+            CareLevel::AboveAverage => *self,
+        }
+    }
+    /// Percentage of recorded ticks that were [CareLevel::Good] or
+    /// [CareLevel::Perfect].
+    fn good_percent(&self) -> u32 {
+        let total = self.good_ticks + self.bad_ticks;
+        if total == 0 {
+            0
+        } else {
+            self.good_ticks * 100 / total
+        }
+    }
+}
+/// Applies `step` once for every whole `interval` contained in `elapsed`.
+fn decay<T>(mut value: T, elapsed: Duration, interval: Duration, step: impl Fn(T) -> T) -> T {
+    let steps = elapsed.as_secs() / interval.as_secs();
+    for _ in 0..steps {
+        value = step(value);
+    }
+    value
+}
 /// The condition of a [Tamagotchi].
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) struct Status {
     care: CareLevel,
     hunger: Hunger,
@@ -169,6 +600,27 @@ pub(crate) struct Status {
     soiled: bool,
     health: Health,
     discipline: Discipline,
+    /// Neglect counter: one per decay tick spent soiled and one per lost
+    /// [Actions::Play] game. Reset by [Status::duck] and by any decay tick
+    /// in which the room is not soiled.
+    neglect: u32,
+    /// Running count of feed/duck/play actions, used for the over-care sick check.
+    care_actions: u32,
+    /// Whether the 'gotchi has died. Terminal: once set, all actions are rejected.
+    dead: bool,
+    /// An outstanding call for attention rolled by [Behavior::reduce], if
+    /// any. Cleared by [Status::attention] or [Status::discipline].
+    ///
+    /// `#[serde(default)]` so saves written before this field existed still
+    /// load instead of being rejected outright.
+    #[serde(default)]
+    call_for_attention: Option<Behavior>,
+    /// Running tally of [CareLevel] observed, folded in once per decay tick
+    /// inside [Status::tick] so its weight tracks real elapsed time rather
+    /// than how often [Tamagotchi::evolve] happens to be polled. Consulted
+    /// by [Tamagotchi::evolve] for evolution branches that depend on
+    /// sustained care rather than a single snapshot.
+    care_history: CareHistory,
 }
 impl Status {
     /// Determines the user's level of care toward a [Tamagotchi].
@@ -189,25 +641,176 @@ impl Status {
     /// [Status] changes for when a [Tamagotchi] eats.
     pub(crate) fn eat(&self) -> Status {
         let hunger = self.hunger.better();
-        Self { hunger, ..*self }
+        let (care_actions, sick) = self.register_care_action();
+        Self {
+            hunger,
+            care_actions,
+            sick,
+            ..*self
+        }
     }
     /// [Status] changes for when a [Tamagotchi] plays.
     pub(crate) fn play(&self) -> Status {
         let mood = self.mood.better();
-        Self { mood, ..*self }
+        let (care_actions, sick) = self.register_care_action();
+        Self {
+            mood,
+            care_actions,
+            sick,
+            ..*self
+        }
     }
-    /// [Status] changes for when a [Tamagotchi] sleeps.
-    pub(crate) fn sleep(&self) -> Status {
-        let mood = self.mood.better();
-        let health = self.health.better();
+    /// [Status] changes for losing the [Actions::Play] mini-game: mood is
+    /// unchanged, but the loss counts toward neglect, same as an uncleaned
+    /// [Status::soiled] tick.
+    pub(crate) fn lose_game(&self) -> Status {
+        let (care_actions, sick) = self.register_care_action();
+        let neglect = self.neglect + 1;
+        let sick = sick || neglect >= NEGLECT_SICK_THRESHOLD;
+        Self {
+            neglect,
+            care_actions,
+            sick,
+            ..*self
+        }
+    }
+    /// [Status] changes for when a [Tamagotchi]'s room is cleaned.
+    pub(crate) fn duck(&self) -> Status {
+        let (care_actions, sick) = self.register_care_action();
+        Self {
+            soiled: false,
+            neglect: 0,
+            care_actions,
+            sick,
+            ..*self
+        }
+    }
+    /// [Status] changes for administering medicine. A no-op unless already
+    /// [Status::sick].
+    pub(crate) fn give_medicine(&self) -> Status {
+        if !self.sick {
+            return *self;
+        }
+        Self { sick: false, ..*self }
+    }
+    /// [Status] changes for toggling the room light. Turning it off puts
+    /// the 'gotchi to [Status::asleep]; turning it back on wakes it.
+    pub(crate) fn toggle_light(&self) -> Status {
+        let light = match self.light {
+            Light::On => Light::Off,
+            Light::Off => Light::On,
+        };
+        Self {
+            light,
+            asleep: matches!(light, Light::Off),
+            ..*self
+        }
+    }
+    /// [Status] changes for acknowledging an outstanding call for attention.
+    /// Clears the call without affecting [Discipline].
+    pub(crate) fn attention(&self) -> Status {
+        Self {
+            call_for_attention: None,
+            ..*self
+        }
+    }
+    /// [Status] changes for disciplining the 'gotchi. Correctly
+    /// disciplining a [Behavior::Bad] call advances [Discipline] toward
+    /// [Discipline::ModelAlien]; disciplining a [Behavior::Good] call (or
+    /// disciplining when there is no outstanding call at all) wrongly
+    /// punishes good behavior and pushes toward [Discipline::Bratty].
+    pub(crate) fn discipline(&self) -> Status {
+        let discipline = match self.call_for_attention {
+            Some(Behavior::Bad) => self.discipline.better(),
+            Some(Behavior::Good) | None => self.discipline.worse(),
+        };
         Self {
+            discipline,
+            call_for_attention: None,
+            ..*self
+        }
+    }
+    /// Counts a feed/duck/play action toward [Status::care_actions] and rolls
+    /// the "over-care" sickness check once [OVERCARE_THRESHOLD] is crossed,
+    /// mirroring the "feed + wash + play > 24" emulator rule.
+    fn register_care_action(&self) -> (u32, bool) {
+        let care_actions = self.care_actions + 1;
+        let sick = self.sick
+            || (care_actions > OVERCARE_THRESHOLD
+                && rand::random::<f64>() < OVERCARE_SICK_PROBABILITY);
+        (care_actions, sick)
+    }
+    /// Applies one step of [BetterOrWorse::worse] for every whole decay
+    /// interval contained in `elapsed`. While `asleep`, [Mood] and [Health]
+    /// recover instead of decay; [Hunger] always decays, awake or not.
+    pub(crate) fn tick(&self, elapsed: Duration) -> Status {
+        let hunger = decay(self.hunger, elapsed, HUNGER_DECAY_INTERVAL, |h| h.worse());
+        let mood = decay(self.mood, elapsed, MOOD_DECAY_INTERVAL, |m| {
+            if self.asleep { m.better() } else { m.worse() }
+        });
+        let health = decay(self.health, elapsed, HEALTH_DECAY_INTERVAL, |h| {
+            if self.asleep { h.better() } else { h.worse() }
+        });
+        let mut soiled = self.soiled;
+        let mut neglect = self.neglect;
+        let mut sick = self.sick;
+        let mut dead = self.dead;
+        let mut call_for_attention = self.call_for_attention;
+        let ticks = elapsed.as_secs() / HUNGER_DECAY_INTERVAL.as_secs();
+        for _ in 0..ticks {
+            if !soiled {
+                soiled = rand::random::<f64>() < SOIL_PROBABILITY;
+            }
+            neglect = if soiled { neglect + 1 } else { 0 };
+            if !sick {
+                sick = neglect >= NEGLECT_SICK_THRESHOLD;
+            }
+            if sick {
+                dead = dead || rand::random::<f64>() < SICK_DEATH_PROBABILITY;
+            }
+            if call_for_attention.is_none() && rand::random::<f64>() < ATTENTION_CALL_PROBABILITY {
+                call_for_attention = Some(Behavior::reduce());
+            }
+        }
+        // A call already outstanding before this `tick()` and still
+        // unanswered at the end of it went unanswered exactly once, no
+        // matter how many simulated ticks (e.g. a fast-forwarded overnight
+        // gap) it spans — the player never had a chance to respond to it
+        // more than once. A call with no whole decay tick in between (a
+        // sub-interval poll) is not counted as ignored.
+        let discipline = if ticks > 0
+            && self.call_for_attention.is_some()
+            && call_for_attention.is_some()
+        {
+            self.discipline.worse()
+        } else {
+            self.discipline
+        };
+        dead = dead || matches!(health, Health::Neglected);
+        let mut next = Self {
+            hunger,
             mood,
             health,
+            soiled,
+            neglect,
+            sick,
+            dead,
+            call_for_attention,
+            discipline,
             ..*self
+        };
+        // Fold in one CareHistory sample per actual decay tick (rather than
+        // once per Tamagotchi::evolve call) so its weight tracks real
+        // elapsed time, not how often the interface happens to be polled.
+        let care_level = next.care_level();
+        for _ in 0..ticks {
+            next.care_history = next.care_history.record(care_level);
         }
+        next
     }
 }
 /// The level of care a [Tamagotchi] receives.
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum CareLevel {
     Bad,
     BelowAverage,
@@ -221,6 +824,7 @@ impl Default for CareLevel {
     }
 }
 /// The hunger level of a [Tamagotchi].
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum Hunger {
     Starving,
     Famished,
@@ -265,11 +869,13 @@ impl BetterOrWorse for Hunger {
     }
 }
 /// The [Tamagotchi]'s room light.
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum Light {
     On,
     Off,
 }
 /// The mood of a [Tamagotchi].
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum Mood {
     Miserable,
     Pessemistic,
@@ -314,6 +920,7 @@ impl BetterOrWorse for Mood {
     }
 }
 /// A [Tamagotchi]'s health condition.
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum Health {
     Neglected,
     Weak,
@@ -360,6 +967,7 @@ impl BetterOrWorse for Health {
 /// The behavior of a [Tamagotchi].
 ///
 /// This may get refactored.
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum Behavior {
     Good,
     Bad,
@@ -373,6 +981,7 @@ impl Behavior {
     }
 }
 /// The level of discipline a [Tamagotchi] displays.
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) enum Discipline {
     Bratty,
     Spoiled,
@@ -391,3 +1000,196 @@ impl Discipline {
         }
     }
 }
+impl BetterOrWorse for Discipline {
+    /// Advances toward [Discipline::ModelAlien], as when a
+    /// [Behavior::Bad] call for attention is correctly disciplined.
+    fn better(&self) -> Self {
+        match self {
+            Discipline::Bratty => Discipline::Spoiled,
+            Discipline::Spoiled => Discipline::Average,
+            Discipline::Average => Discipline::Goody2Shoes,
+            Discipline::Goody2Shoes => Discipline::ModelAlien,
+            Discipline::ModelAlien => Discipline::ModelAlien,
+        }
+    }
+    /// Slides toward [Discipline::Bratty], as when a call for attention is
+    /// ignored or good behavior is wrongly disciplined.
+    fn worse(&self) -> Self {
+        match self {
+            Discipline::Bratty => Discipline::Bratty,
+            Discipline::Spoiled => Discipline::Bratty,
+            Discipline::Average => Discipline::Spoiled,
+            Discipline::Goody2Shoes => Discipline::Average,
+            Discipline::ModelAlien => Discipline::Goody2Shoes,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_status() -> Status {
+        Status {
+            care: CareLevel::Good,
+            hunger: Hunger::Full,
+            light: Light::On,
+            asleep: false,
+            mood: Mood::Cheerful,
+            sick: false,
+            soiled: false,
+            health: Health::Strong,
+            discipline: Discipline::Average,
+            neglect: 0,
+            care_actions: 0,
+            dead: false,
+            call_for_attention: None,
+            care_history: CareHistory::default(),
+        }
+    }
+
+    fn sample_tamagotchi() -> Tamagotchi {
+        let now = SystemTime::now();
+        Tamagotchi {
+            name: "Test".to_string(),
+            gender: Gender::Female,
+            age: 0,
+            weight: 10.0,
+            form: Form::Tonmarutchi,
+            status: sample_status(),
+            birth: now,
+            last_updated: now,
+            last_thought: None,
+        }
+    }
+
+    #[test]
+    fn duck_clears_soiled_and_neglect() {
+        let status = Status {
+            soiled: true,
+            neglect: 3,
+            ..sample_status()
+        };
+        let ducked = status.duck();
+        assert!(!ducked.soiled);
+        assert_eq!(ducked.neglect, 0);
+    }
+
+    #[test]
+    fn give_medicine_is_noop_when_not_sick() {
+        let status = Status {
+            sick: false,
+            health: Health::Weak,
+            ..sample_status()
+        };
+        let dosed = status.give_medicine();
+        assert!(matches!(dosed.health, Health::Weak));
+    }
+
+    #[test]
+    fn give_medicine_clears_sick() {
+        let status = Status {
+            sick: true,
+            ..sample_status()
+        };
+        let dosed = status.give_medicine();
+        assert!(!dosed.sick);
+    }
+
+    #[test]
+    fn care_history_good_percent_buckets() {
+        let history = CareHistory::default()
+            .record(CareLevel::Good)
+            .record(CareLevel::Good)
+            .record(CareLevel::Good)
+            .record(CareLevel::Bad);
+        assert_eq!(history.good_percent(), 75);
+    }
+
+    #[test]
+    fn care_history_good_percent_is_zero_with_no_ticks() {
+        assert_eq!(CareHistory::default().good_percent(), 0);
+    }
+
+    #[test]
+    fn tick_folds_one_care_history_sample_per_decay_tick() {
+        // Worst-possible stats saturate under `worse()`, so CareLevel::Bad
+        // holds for the whole call regardless of elapsed time — letting us
+        // assert the sample count scales with ticks, not with how many
+        // times `tick()` happens to be called.
+        let worst = Status {
+            hunger: Hunger::Starving,
+            mood: Mood::Miserable,
+            health: Health::Neglected,
+            discipline: Discipline::Bratty,
+            ..sample_status()
+        };
+        let ticked = worst.tick(HUNGER_DECAY_INTERVAL * 3);
+        assert_eq!(ticked.care_history.bad_ticks, 3);
+    }
+
+    #[test]
+    fn discipline_saturates_at_bounds() {
+        assert!(matches!(Discipline::ModelAlien.better(), Discipline::ModelAlien));
+        assert!(matches!(Discipline::Bratty.worse(), Discipline::Bratty));
+    }
+
+    #[test]
+    fn tick_does_not_advance_last_updated_before_a_full_decay_interval() {
+        // Several sub-interval ticks must accumulate rather than each one
+        // resetting `last_updated` to "now" — see
+        // eureka-cpu/tamagotchi-taminaru#chunk0-1.
+        let tamagotchi = sample_tamagotchi();
+        let before = tamagotchi.last_updated;
+        let ticked = tamagotchi.tick(Duration::from_secs(1));
+        assert_eq!(ticked.last_updated, before);
+    }
+
+    #[test]
+    fn load_fast_forwards_decay_by_elapsed_wall_clock_time() {
+        let path =
+            std::env::temp_dir().join(format!("tamagotchi_test_load_{}.json", std::process::id()));
+        let mut stale = sample_tamagotchi();
+        stale.last_updated = SystemTime::now() - HUNGER_DECAY_INTERVAL * 3;
+        stale.save(&path).expect("save should succeed");
+        let loaded = Tamagotchi::load(&path).expect("load should succeed");
+        fs::remove_file(&path).ok();
+        assert!(matches!(loaded.status.hunger, Hunger::Famished));
+        assert!(matches!(loaded.status.mood, Mood::Optimistic));
+        assert!(matches!(loaded.status.health, Health::Normal));
+    }
+
+    #[test]
+    fn play_rejects_guess_count_mismatch() {
+        let tamagotchi = sample_tamagotchi();
+        let (unchanged, result) = tamagotchi.play(&[Direction::Left]);
+        assert!(result.rounds.is_empty());
+        assert!(!result.won);
+        assert_eq!(unchanged.weight, tamagotchi.weight);
+    }
+
+    #[test]
+    fn play_with_correct_guess_count_produces_one_round_per_guess() {
+        let tamagotchi = sample_tamagotchi();
+        let guesses = [Direction::Left, Direction::Right, Direction::Left];
+        let (_, result) = tamagotchi.play(&guesses);
+        assert_eq!(result.rounds.len(), PLAY_GAME_ROUNDS);
+    }
+
+    #[test]
+    fn evolve_hatches_egg_after_age_threshold() {
+        let mut hatching = sample_tamagotchi();
+        hatching.form = Form::Tamago;
+        hatching.age = EGG_HATCH_AGE_MINUTES;
+        let evolved = hatching.evolve();
+        assert!(matches!(evolved.form, Form::Shirobabytchi));
+    }
+
+    #[test]
+    fn evolve_does_not_advance_form_before_age_threshold() {
+        let mut young = sample_tamagotchi();
+        young.form = Form::Tamago;
+        young.age = EGG_HATCH_AGE_MINUTES - 1;
+        let evolved = young.evolve();
+        assert!(matches!(evolved.form, Form::Tamago));
+    }
+}